@@ -2,6 +2,8 @@
 
 use std::collections::HashMap;
 use std::fmt;
+use std::fs;
+use std::path::Path;
 
 /// A constant representing the maximum retries.
 pub const MAX_RETRIES: u32 = 3;
@@ -18,6 +20,7 @@ pub enum AppError {
     NotFound,
     InvalidInput,
     InternalError(String),
+    Io(String),
 }
 
 /// A trait for things that can be validated.
@@ -65,6 +68,131 @@ impl fmt::Display for User {
     }
 }
 
+/// Builds greetings from a template containing a `{name}` placeholder.
+pub struct Greeter {
+    template: String,
+}
+
+impl Greeter {
+    /// Creates a new greeter with the given greeting prefix.
+    pub fn new(greeting: &str) -> Self {
+        Greeter {
+            template: format!("{} {{name}}", greeting),
+        }
+    }
+
+    /// Creates a greeter from an explicit template, which must contain at
+    /// least one `{name}` placeholder. Literal braces can be escaped as
+    /// `{{`/`}}`.
+    pub fn with_template(template: &str) -> Result<Greeter> {
+        if !contains_name_placeholder(template) {
+            return Err(AppError::InvalidInput);
+        }
+        Ok(Greeter {
+            template: template.to_string(),
+        })
+    }
+
+    /// Loads a greeting prefix from a text file on disk, trimming trailing newlines.
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Greeter> {
+        let contents = fs::read_to_string(path).map_err(|e| AppError::Io(e.to_string()))?;
+        Ok(Greeter::new(contents.trim_end_matches(['\n', '\r'])))
+    }
+
+    /// Returns a greeting for `thing` by substituting it into this greeter's template.
+    pub fn greeting(&self, thing: &str) -> String {
+        render_template(&self.template, thing)
+    }
+
+    /// Returns a greeting for `name` when present, falling back to a generic
+    /// greeting for the world when absent.
+    pub fn greeting_optional(&self, name: Option<&str>) -> String {
+        match name {
+            Some(name) => self.greeting(&format!("{}!", name)),
+            None => self.greeting("world!"),
+        }
+    }
+}
+
+impl Default for Greeter {
+    fn default() -> Self {
+        Greeter::new("Hello,")
+    }
+}
+
+/// A piece of a parsed greeting template.
+enum TemplatePart {
+    Literal(String),
+    Name,
+}
+
+/// Parses `template` into literal text and `{name}` placeholders, treating
+/// `{{` and `}}` as escaped literal braces. A `{` that is never closed by a
+/// matching `}`, or whose token isn't `name`, is kept as literal text.
+fn parse_template(template: &str) -> Vec<TemplatePart> {
+    let mut parts = Vec::new();
+    let mut literal = String::new();
+    let mut chars = template.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '{' if chars.peek() == Some(&'{') => {
+                chars.next();
+                literal.push('{');
+            }
+            '}' if chars.peek() == Some(&'}') => {
+                chars.next();
+                literal.push('}');
+            }
+            '{' => {
+                let mut token = String::new();
+                let mut closed = false;
+                for c2 in chars.by_ref() {
+                    if c2 == '}' {
+                        closed = true;
+                        break;
+                    }
+                    token.push(c2);
+                }
+                if closed && token == "name" {
+                    if !literal.is_empty() {
+                        parts.push(TemplatePart::Literal(std::mem::take(&mut literal)));
+                    }
+                    parts.push(TemplatePart::Name);
+                } else {
+                    literal.push('{');
+                    literal.push_str(&token);
+                    if closed {
+                        literal.push('}');
+                    }
+                }
+            }
+            _ => literal.push(c),
+        }
+    }
+    if !literal.is_empty() {
+        parts.push(TemplatePart::Literal(literal));
+    }
+    parts
+}
+
+/// Substitutes `{name}` in `template` with `value`.
+fn render_template(template: &str, value: &str) -> String {
+    parse_template(template)
+        .into_iter()
+        .map(|part| match part {
+            TemplatePart::Literal(s) => s,
+            TemplatePart::Name => value.to_string(),
+        })
+        .collect()
+}
+
+/// Returns whether `template` contains an unescaped `{name}` placeholder.
+fn contains_name_placeholder(template: &str) -> bool {
+    parse_template(template)
+        .iter()
+        .any(|part| matches!(part, TemplatePart::Name))
+}
+
 /// A helper function to create a greeting.
 pub fn greet(user: &User) -> String {
     let name = user.display_name();
@@ -73,7 +201,12 @@ pub fn greet(user: &User) -> String {
 
 /// Format a greeting message.
 fn format_greeting(name: &str) -> String {
-    format!("Hello, {}!", name)
+    Greeter::default().greeting(&format!("{}!", name))
+}
+
+/// Greets `name` when given, or falls back to a generic greeting.
+pub fn greet_optional(name: Option<&str>) -> String {
+    Greeter::default().greeting_optional(name)
 }
 
 /// Process multiple users.
@@ -116,4 +249,67 @@ mod tests {
         let greeting = greet(&user);
         assert!(greeting.contains("Charlie"));
     }
+
+    #[test]
+    fn test_greeter_from_file() {
+        let path = std::env::temp_dir().join("sample_greeter_from_file_test.txt");
+        fs::write(&path, "Howdy,\n").unwrap();
+        let greeter = Greeter::from_file(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+        assert_eq!(greeter.greeting("Frank!"), "Howdy, Frank!");
+    }
+
+    #[test]
+    fn test_greeter_from_file_trims_doubled_trailing_line_endings() {
+        let path = std::env::temp_dir().join("sample_greeter_from_file_crlf_test.txt");
+        fs::write(&path, "Howdy,\r\n\r\n").unwrap();
+        let greeter = Greeter::from_file(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+        assert_eq!(greeter.greeting("Frank!"), "Howdy, Frank!");
+    }
+
+    #[test]
+    fn test_greeter_from_file_missing_path_is_io_error() {
+        let result = Greeter::from_file("/nonexistent/path/to/greeting.txt");
+        assert!(matches!(result, Err(AppError::Io(_))));
+    }
+
+    #[test]
+    fn test_greet_optional_some() {
+        let greeting = greet_optional(Some("Dana"));
+        assert!(greeting.contains("Dana"));
+    }
+
+    #[test]
+    fn test_greet_optional_none() {
+        let greeting = greet_optional(None);
+        assert_eq!(greeting, "Hello, world!");
+    }
+
+    #[test]
+    fn test_greeter_with_template() {
+        let greeter = Greeter::with_template("Welcome back, {name} — good to see you!").unwrap();
+        assert_eq!(
+            greeter.greeting("Erin"),
+            "Welcome back, Erin — good to see you!"
+        );
+    }
+
+    #[test]
+    fn test_greeter_with_template_requires_name_token() {
+        let result = Greeter::with_template("Welcome back!");
+        assert!(matches!(result, Err(AppError::InvalidInput)));
+    }
+
+    #[test]
+    fn test_greeter_with_template_rejects_escaped_only_name() {
+        let result = Greeter::with_template("Hello {{name}} literal");
+        assert!(matches!(result, Err(AppError::InvalidInput)));
+    }
+
+    #[test]
+    fn test_render_template_keeps_unterminated_brace_literal() {
+        let greeter = Greeter::with_template("Hi {name} total: {amt").unwrap();
+        assert_eq!(greeter.greeting("Erin"), "Hi Erin total: {amt");
+    }
 }